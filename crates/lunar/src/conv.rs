@@ -1,13 +1,18 @@
 use std::ops::RangeInclusive;
 
-use jiff::ToSpan;
 use jiff::civil::{Date as Greg, Era};
+use jiff::ToSpan;
 use thiserror::Error;
 
+use crate::Month;
+
 /// Period during which the calendar was switched from Julian to Gregorian. All
 /// dates within this period (exclusive) are invalid.
 const ADJ: RangeInclusive<Greg> = Greg::constant(1752, 09, 02)..=Greg::constant(1752, 09, 14);
 
+/// R.D. of 1 Tishrei, Hebrew year 1 (the epoch of the Hebrew calendar).
+const HEBREW_EPOCH: i64 = -1373427;
+
 /// Rata Die date number.
 #[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct RataDie(u64);
@@ -144,6 +149,175 @@ pub enum Error {
     /// Within Gregorian adjustment.
     #[error("within Gregorian adjustment")]
     Adj,
+    /// Hebrew year out of representable range.
+    #[error("Hebrew year out of representable range")]
+    Range,
+}
+
+/// A Hebrew calendar date.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Hebrew {
+    /// Hebrew year (anno mundi).
+    pub year: i32,
+    /// Hebrew month.
+    pub month: Month,
+    /// Day of the month (1-30).
+    pub day: u8,
+}
+
+/// Whether `year` is a leap year (one of 7 years in each 19-year Metonic
+/// cycle, adding a thirteenth month, Adar I).
+fn is_leap_year(year: i64) -> bool {
+    (7 * year + 1).rem_euclid(19) < 7
+}
+
+/// Days elapsed between the (mean) molad of Tishrei for `year` and the
+/// epoch, after applying the four dechiyot (postponement rules) that keep
+/// Rosh Hashana off of forbidden weekdays.
+fn elapsed_days(year: i64) -> i64 {
+    // Months elapsed since the epoch, and the molad's hours/parts of the day
+    let months = (235 * year - 234) / 19;
+    let parts = 204 + 793 * (months % 1080);
+    let hours = 5 + 12 * months + 793 * (months / 1080) + parts / 1080;
+    let day = 1 + 29 * months + hours / 24;
+    let parts_of_day = (hours % 24) * 1080 + parts % 1080;
+
+    // Molad zakein: a conjunction at or after noon (18 "hours" of 1080 parts
+    // into a day that begins at 6 pm) postpones Rosh Hashana to the next day. GaTaRaD and
+    // BeTuTaKPaT are the common/leap-year refinements of that same rule.
+    let alt_day = if parts_of_day >= 19_440
+        || (day % 7 == 2 && parts_of_day >= 9_924 && !is_leap_year(year))
+        || (day % 7 == 1 && parts_of_day >= 16_789 && is_leap_year(year - 1))
+    {
+        day + 1
+    } else {
+        day
+    };
+
+    // Lo ADU Rosh: Rosh Hashana may not fall on Sunday, Wednesday, or Friday
+    if matches!(alt_day % 7, 0 | 3 | 5) {
+        alt_day + 1
+    } else {
+        alt_day
+    }
+}
+
+/// R.D. of 1 Tishrei for the given Hebrew year.
+fn new_year(year: i64) -> i64 {
+    // `elapsed_days(1)` counts 1 day elapsed since the epoch itself, so the
+    // epoch must be offset by one to land on R.D. -1373427 for year 1
+    HEBREW_EPOCH - 1 + elapsed_days(year)
+}
+
+/// Length, in days, of the given Hebrew year (one of 353-355 for a common
+/// year, or 383-385 for a leap year). This is the "Four Gates" value that
+/// selects a year's keviyah (month-length pattern).
+fn year_length(year: i64) -> i64 {
+    new_year(year + 1) - new_year(year)
+}
+
+/// Whether Cheshvan has 30 days (a "full" year) this Hebrew year.
+fn long_cheshvan(year: i64) -> bool {
+    year_length(year) % 10 == 5
+}
+
+/// Whether Kislev has 29 days (a "deficient" year) this Hebrew year.
+fn short_kislev(year: i64) -> bool {
+    year_length(year) % 10 == 3
+}
+
+/// Last month number (12 for a common year, 13 for a leap year).
+fn last_month(year: i64) -> i64 {
+    if is_leap_year(year) { 13 } else { 12 }
+}
+
+/// Number of days in the given month (numbered as [`Month`], 1-13) of the
+/// given Hebrew year.
+fn last_day(year: i64, month: i64) -> i64 {
+    match month {
+        2 | 4 | 6 | 10 | 13 => 29,
+        12 if !is_leap_year(year) => 29,
+        8 if !long_cheshvan(year) => 29,
+        9 if short_kislev(year) => 29,
+        _ => 30,
+    }
+}
+
+/// Converts a month number (1-13) to a [`Month`].
+fn month_from_number(month: i64) -> Month {
+    match month {
+        1 => Month::Nisan,
+        2 => Month::Iyyar,
+        3 => Month::Sivan,
+        4 => Month::Tamuz,
+        5 => Month::Av,
+        6 => Month::Elul,
+        7 => Month::Tishrei,
+        8 => Month::Cheshvan,
+        9 => Month::Kislev,
+        10 => Month::Tevet,
+        11 => Month::Shvat,
+        12 => Month::Adar1,
+        13 => Month::Adar2,
+        _ => unreachable!("Hebrew month out of range 1-13"),
+    }
+}
+
+/// R.D. of the given day (1-30) of the given month (1-13, [`Month`]
+/// numbering) of the given Hebrew year.
+fn fixed_from_ymd(year: i64, month: i64, day: i64) -> i64 {
+    // Calendar-order days before `month`: the rest of Tishrei..Adar(II) plus
+    // any of Nisan..Elul already passed, since the year begins at Tishrei
+    // but months are numbered starting from Nisan
+    let days = if month < 7 {
+        (7..=last_month(year))
+            .chain(1..month)
+            .map(|m| last_day(year, m))
+            .sum::<i64>()
+    } else {
+        (7..month).map(|m| last_day(year, m)).sum::<i64>()
+    };
+
+    new_year(year) + days + day - 1
+}
+
+impl TryFrom<RataDie> for Hebrew {
+    type Error = Error;
+
+    fn try_from(rata: RataDie) -> Result<Self, Self::Error> {
+        let date = rata.0 as i64;
+
+        // Approximate the year from the mean year length, then correct by
+        // walking forward from there; the estimate is never more than one
+        // year too late, which the final step back covers
+        let mut year = ((date - HEBREW_EPOCH) as f64 / (35_975_351. / 98_496.)) as i64 + 1;
+        while new_year(year) <= date {
+            year += 1;
+        }
+        year -= 1;
+
+        // Walk the year's months in calendar order (Tishrei..Adar(II), then
+        // Nisan..Elul) until we find the one `date` falls within
+        let month = (7..=last_month(year))
+            .chain(1..=6)
+            .find(|&m| date <= fixed_from_ymd(year, m, last_day(year, m)))
+            .expect("date falls within its own Hebrew year");
+
+        let day = date - fixed_from_ymd(year, month, 1) + 1;
+
+        Ok(Self {
+            year: i32::try_from(year).map_err(|_| Error::Range)?,
+            month: month_from_number(month),
+            day: u8::try_from(day).map_err(|_| Error::Range)?,
+        })
+    }
+}
+
+impl From<Hebrew> for RataDie {
+    fn from(hebrew: Hebrew) -> Self {
+        let Hebrew { year, month, day } = hebrew;
+        Self(fixed_from_ymd(i64::from(year), month as i64, i64::from(day)) as u64)
+    }
 }
 
 #[cfg(test)]
@@ -162,4 +336,25 @@ mod tests {
         assert_eq!(rata, RataDie(739550));
         assert_eq!(greg, Greg::from(rata));
     }
+
+    #[test]
+    fn hebrew_works() {
+        // Declare date
+        let greg = Greg::constant(2025, 10, 26);
+        let rata = RataDie::try_from(greg).unwrap();
+
+        // Calculate Hebrew date
+        let hebrew = Hebrew::try_from(rata).unwrap();
+
+        // Ensure matches expectation
+        assert_eq!(
+            hebrew,
+            Hebrew {
+                year: 5786,
+                month: Month::Cheshvan,
+                day: 4,
+            }
+        );
+        assert_eq!(rata, RataDie::from(hebrew));
+    }
 }
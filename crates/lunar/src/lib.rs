@@ -1,6 +1,13 @@
 mod conv;
 
+pub use conv::{Hebrew, RataDie};
+
 /// Hebrew months.
+///
+/// Numbered as in the classical sources, starting from Nisan. In a common
+/// (non-leap) year, [`Adar1`](Self::Adar1) stands in for the single month of
+/// Adar and [`Adar2`](Self::Adar2) is unused.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Month {
     Nisan = 1,
     Iyyar,
@@ -1,8 +1,8 @@
 use jiff::civil::Date;
 use jiff::tz::TimeZone;
-use jiff::{Error, SignedDuration, Timestamp};
+use jiff::{SignedDuration, Timestamp};
 
-use crate::{Day, Geo};
+use crate::{Anchor, Day, Error, Geo};
 
 /// Convert timestamp to Julian date.
 fn ts2j(ts: Timestamp) -> f64 {
@@ -10,26 +10,27 @@ fn ts2j(ts: Timestamp) -> f64 {
 }
 
 /// Convert Julian date to timestamp.
-fn j2ts(j: f64) -> Result<Timestamp, Error> {
-    Timestamp::from_duration(SignedDuration::from_secs_f64((j - 2440587.5) * 86400.))
+pub(crate) fn j2ts(j: f64) -> Result<Timestamp, Error> {
+    Ok(Timestamp::from_duration(SignedDuration::from_secs_f64(
+        (j - 2440587.5) * 86400.,
+    ))?)
 }
 
-/// Calculate sunrise and sunset times.
+/// Julian date of the given civil date at noon UTC.
+pub(crate) fn noon_jd(date: Date) -> Result<f64, Error> {
+    Ok(ts2j(
+        date.at(12, 0, 0, 0).to_zoned(TimeZone::UTC)?.timestamp(),
+    ))
+}
+
+/// Sun's mean anomaly and apparent ecliptic longitude (both in degrees),
+/// given `jstar`, the number of days elapsed since the J2000 epoch
+/// (2451545.0 Julian date).
 ///
 /// See more [here].
 ///
-/// [here]: https://en.wikipedia.org/wiki/Sunrise_equation
-pub fn suntimes(date: Date, place: Geo) -> Result<Day, Error> {
-    // Fix timestamp to noon
-    let tnoon = date.at(12, 0, 0, 0).to_zoned(TimeZone::UTC)?.timestamp();
-
-    // Julian day
-    let jdate = ts2j(tnoon);
-    let day_n = (jdate - (2451545. + 0.0009) + 69.184 / 86400.).ceil();
-
-    // Mean solar time
-    let jstar = day_n + 0.0009 - place.lon / 360.;
-
+/// [here]: https://en.wikipedia.org/wiki/Position_of_the_Sun
+pub(crate) fn anomaly_longitude(jstar: f64) -> (f64, f64) {
     // Solar mean anomaly
     let m_deg = (357.5291 + 0.98560028 * jstar) % 360.;
     let m_rad = m_deg.to_radians();
@@ -40,37 +41,107 @@ pub fn suntimes(date: Date, place: Geo) -> Result<Day, Error> {
 
     // Ecliptic longitude
     let l_deg = (m_deg + c_deg + 180. + 102.9372) % 360.;
-    let l_rad = l_deg.to_radians();
 
-    // Solar transit
-    let trans = 2451545.0 + jstar + 0.0053 * m_rad.sin() - 0.0069 * (2. * l_rad).sin();
+    (m_deg, l_deg)
+}
 
-    // Declination of the sun
-    let sin_d = l_rad.sin() * 23.4397_f64.to_radians().sin();
-    let cos_d = sin_d.asin().cos();
+/// Precomputed solar quantities for a day, shared between sunrise/sunset and
+/// other altitude-based times.
+#[derive(Clone, Debug)]
+pub(crate) struct Sun {
+    /// Geographic coordinate.
+    place: Geo,
+    /// Solar transit (Julian date).
+    transit: f64,
+    /// Sine of the sun's declination.
+    sin_d: f64,
+    /// Cosine of the sun's declination.
+    cos_d: f64,
+}
 
-    // Hour angle
-    let cos_w = {
-        let lat = place.lat.to_radians();
-        let elv = (-0.833_f64 - (2.076 * place.elv.sqrt() / 60.)).to_radians();
-        (elv.sin() - lat.sin() * sin_d) / (lat.cos() * cos_d)
-    };
-    let w_rad = cos_w.acos();
-    let w_deg = w_rad.to_degrees();
+impl Sun {
+    /// Computes the sun's position (transit and declination) for a given
+    /// date and place.
+    fn new(date: Date, place: Geo) -> Result<Self, Error> {
+        // Julian day
+        let jdate = noon_jd(date)?;
+        let day_n = (jdate - (2451545. + 0.0009) + 69.184 / 86400.).ceil();
+
+        // Mean solar time
+        let jstar = day_n + 0.0009 - place.lon / 360.;
+
+        // Solar mean anomaly and ecliptic longitude
+        let (m_deg, l_deg) = anomaly_longitude(jstar);
+        let m_rad = m_deg.to_radians();
+        let l_rad = l_deg.to_radians();
+
+        // Solar transit
+        let transit = 2451545.0 + jstar + 0.0053 * m_rad.sin() - 0.0069 * (2. * l_rad).sin();
+
+        // Declination of the sun
+        let sin_d = l_rad.sin() * 23.4397_f64.to_radians().sin();
+        let cos_d = sin_d.asin().cos();
+
+        Ok(Self {
+            place,
+            transit,
+            sin_d,
+            cos_d,
+        })
+    }
 
-    // Sunrise and sunset
-    let jrise = trans - w_deg / 360.;
-    let jdown = trans + w_deg / 360.;
+    /// Computes the time at which the sun reaches `altitude` degrees above
+    /// the horizon (negative for angles below it), anchored to the morning
+    /// or evening side of the day.
+    ///
+    /// Returns [`Error::Polar`] if `altitude` is never reached, i.e. the
+    /// hour angle's cosine falls outside `[-1, 1]`.
+    pub(crate) fn altitude_time(&self, altitude: f64, anchor: Anchor) -> Result<Timestamp, Error> {
+        let lat = self.place.lat.to_radians();
+        let h = altitude.to_radians();
+
+        // Hour angle
+        let cos_w = (h.sin() - lat.sin() * self.sin_d) / (lat.cos() * self.cos_d);
+        if !(-1. ..=1.).contains(&cos_w) {
+            return Err(Error::Polar);
+        }
+        let w_deg = cos_w.acos().to_degrees();
+
+        // Morning side is before transit, evening side is after
+        let j = match anchor {
+            Anchor::Sunrise => self.transit - w_deg / 360.,
+            Anchor::Sundown => self.transit + w_deg / 360.,
+        };
 
-    // Convert to timestamp
-    let rise = j2ts(jrise)?;
-    let down = j2ts(jdown)?;
+        j2ts(j)
+    }
+
+    /// The geographic coordinate this position was computed for.
+    pub(crate) fn place(&self) -> Geo {
+        self.place
+    }
+}
+
+/// Calculate sunrise and sunset times.
+///
+/// See more [here].
+///
+/// [here]: https://en.wikipedia.org/wiki/Sunrise_equation
+pub fn suntimes(date: Date, place: Geo) -> Result<Day, Error> {
+    // Horizon dip due to atmospheric refraction and elevation
+    let dip = -0.833 - 2.076 * place.elv.sqrt() / 60.;
+
+    let sun = Sun::new(date, place)?;
+
+    // Sunrise and sunset
+    let rise = sun.altitude_time(dip, Anchor::Sunrise)?;
+    let down = sun.altitude_time(dip, Anchor::Sundown)?;
 
     Ok(Day {
         date,
         rise,
         down,
-        _prv: (),
+        sun,
     })
 }
 
@@ -1,11 +1,13 @@
 use jiff::civil::Date;
-use jiff::{Error, Timestamp};
+use jiff::Timestamp;
 
 /// Suntimes calculations.
 mod calc;
+/// Seasonal tekufot (equinoxes and solstices).
+pub mod season;
 
 /// Suntimes times for a given day.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Day {
     /// Calendar date.
     pub date: Date,
@@ -13,8 +15,8 @@ pub struct Day {
     pub rise: Timestamp,
     /// Sunset time.
     pub down: Timestamp,
-    /// Private field.
-    _prv: (),
+    /// Precomputed solar quantities, reused by [`Day::altitude_time`].
+    sun: calc::Sun,
 }
 
 impl Day {
@@ -22,9 +24,36 @@ impl Day {
     pub fn new(date: Date, place: Geo) -> Result<Self, Error> {
         calc::suntimes(date, place)
     }
+
+    /// Computes the time at which the sun reaches `altitude` degrees above
+    /// the horizon (negative for angles below it), anchored to the morning
+    /// ([`Anchor::Sunrise`]) or evening ([`Anchor::Sundown`]) side of the
+    /// day.
+    ///
+    /// Returns [`Error::Polar`] if the sun never reaches this altitude on
+    /// this day at this latitude.
+    pub fn altitude_time(&self, altitude: f64, anchor: Anchor) -> Result<Timestamp, Error> {
+        self.sun.altitude_time(altitude, anchor)
+    }
+
+    /// Recomputes this day's sunrise and sunset at sea level (elevation
+    /// zero), regardless of the place's actual elevation.
+    ///
+    /// Some opinions define the variable (halakhic) hours without regard to
+    /// elevation, even at an elevated place.
+    pub fn sea_level(&self) -> Result<Self, Error> {
+        calc::suntimes(
+            self.date,
+            Geo {
+                elv: 0.,
+                ..self.sun.place()
+            },
+        )
+    }
 }
 
 /// A geographic coordinate on Earth.
+#[derive(Clone, Copy, Debug)]
 pub struct Geo {
     /// Longitude (coordinate).
     pub lon: f64,
@@ -33,3 +62,26 @@ pub struct Geo {
     /// Elevation (meters).
     pub elv: f64,
 }
+
+/// Relative anchor.
+///
+/// Anchor point from which an offset (angle or duration) is computed.
+#[derive(Clone, Copy, Debug)]
+pub enum Anchor {
+    /// Morning side of the day, before sunrise.
+    Sunrise,
+    /// Evening side of the day, after sundown.
+    Sundown,
+}
+
+/// An error computing suntimes.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The sun never reaches the requested altitude on this day at this
+    /// latitude (e.g. polar day or night).
+    #[error("sun never reaches the requested altitude")]
+    Polar,
+    /// Error converting between timestamps.
+    #[error(transparent)]
+    Time(#[from] jiff::Error),
+}
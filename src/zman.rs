@@ -1,12 +1,31 @@
 //! Daily zmanim.
 
-use jiff::Timestamp;
+use jiff::{SignedDuration, Timestamp};
+use solar::Anchor;
+
+/// Halakhic opinion.
+///
+/// Governs which anchor points bound the 12 variable (halakhic) hours of
+/// the day, used by [`Timepoint::Hour`].
+#[derive(Clone, Copy, Debug)]
+pub enum Opinion {
+    /// Vilna Gaon (the GRA).
+    ///
+    /// The day runs from [_sunrise_](Zman::Netz) to [_sunset_](Zman::Shekiah).
+    Gra,
+    /// Magen Avraham (the MGA).
+    ///
+    /// The day runs from [_daybreak_](Zman::Alot) to [_nightfall_](Zman::Tzet).
+    MagenAvraham,
+}
 
 /// Zmanim calculations.
+#[derive(Clone, Debug)]
 pub enum Timepoint {
     /// Relative hour.
     ///
-    /// Number of twelfths (halakhic hours) between sunrise and sunset.
+    /// Number of twelfths (halakhic hours) between the start and end of the
+    /// day, per the governing [`Opinion`].
     Hour(f32),
     /// Offset angle.
     ///
@@ -18,31 +37,78 @@ pub enum Timepoint {
         anchor: Anchor,
         /// Relative offset.
         ///
-        /// Angle of the sun relative to the anchor point.
+        /// Angle of the sun below the horizon, relative to the anchor
+        /// point.
         offset: f32,
     },
-}
-
-/// Relative anchor.
-pub enum Anchor {
-    Sunrise,
-    Sundown,
+    /// Fixed-minute offset.
+    ///
+    /// Some opinions define dawn/dusk as a fixed duration before sunrise or
+    /// after sunset, rather than by depression angle.
+    Minutes {
+        /// Relative anchor.
+        ///
+        /// Anchor point from which the offset is computed.
+        anchor: Anchor,
+        /// Relative offset.
+        ///
+        /// Minutes before (sunrise) or after (sundown) the anchor point.
+        minutes: f32,
+    },
 }
 
 impl Timepoint {
-    /// Compute the zman for a given day.
-    pub fn compute(&self, day: solar::Day) -> Timestamp {
+    /// Compute the zman for a given day, under the given halakhic opinion
+    /// and [`ZmanimConfig`].
+    ///
+    /// The `opinion` and `config.use_elevation` only affect
+    /// [`Timepoint::Hour`]; an [`Angle`](Self::Angle) or
+    /// [`Minutes`](Self::Minutes) timepoint is always anchored to the sun's
+    /// actual position.
+    ///
+    /// Returns [`solar::Error::Polar`] if an [`Angle`](Self::Angle)
+    /// timepoint's altitude is never reached on this day at this latitude.
+    pub fn compute(
+        &self,
+        day: solar::Day,
+        opinion: Opinion,
+        config: &ZmanimConfig,
+    ) -> Result<Timestamp, solar::Error> {
         match self {
             Timepoint::Hour(hour) => {
+                // Elevation only bears on the variable-hour span, per config
+                let day = if config.use_elevation {
+                    day
+                } else {
+                    day.sea_level()?
+                };
+
+                // Start and end of the halakhic day, per the opinion
+                let (start, end) = match opinion {
+                    Opinion::Gra => (day.rise, day.down),
+                    Opinion::MagenAvraham => (
+                        config.alot.compute(day.clone(), opinion, config)?,
+                        config.tzet.compute(day.clone(), opinion, config)?,
+                    ),
+                };
                 // Compute day length
-                let span = day.rise.duration_until(day.down);
+                let span = start.duration_until(end);
                 // Compute day offset
                 let offs = span.mul_f32(hour / 12.);
                 // Compute zman
-                day.rise + offs
+                Ok(start + offs)
             }
             Timepoint::Angle { anchor, offset } => {
-                unimplemented!()
+                // The sun is below the horizon by `offset` degrees
+                day.altitude_time(-f64::from(*offset), *anchor)
+            }
+            Timepoint::Minutes { anchor, minutes } => {
+                // Fixed duration before sunrise or after sundown
+                let offs = SignedDuration::from_secs_f64(f64::from(*minutes) * 60.);
+                Ok(match anchor {
+                    Anchor::Sunrise => day.rise - offs,
+                    Anchor::Sundown => day.down + offs,
+                })
             }
         }
     }
@@ -138,7 +204,7 @@ impl From<Zman> for Timepoint {
         match value {
             Zman::Alot         => Timepoint::Angle {
                 anchor: Anchor::Sunrise,
-                offset: 90. - 16.1,
+                offset: 16.1,
             },
             Zman::Netz         => Timepoint::Hour(0.),
             Zman::Shema        => Timepoint::Hour(3.),
@@ -156,6 +222,57 @@ impl From<Zman> for Timepoint {
     }
 }
 
+impl Zman {
+    /// Compute this zman for a given day, under the given halakhic opinion
+    /// and [`ZmanimConfig`].
+    ///
+    /// [`Zman::Alot`] and [`Zman::Tzet`] are taken directly from the
+    /// config, rather than the fixed 16.1°/8.5° depression angles, so that
+    /// a custom dawn/dusk definition is honoured everywhere, including as
+    /// the bounds of the [_Magen Avraham_](Opinion::MagenAvraham) day.
+    pub fn compute_with(
+        self,
+        day: solar::Day,
+        opinion: Opinion,
+        config: &ZmanimConfig,
+    ) -> Result<Timestamp, solar::Error> {
+        let timepoint = match self {
+            Zman::Alot => config.alot.clone(),
+            Zman::Tzet => config.tzet.clone(),
+            _ => Timepoint::from(self),
+        };
+        timepoint.compute(day, opinion, config)
+    }
+}
+
+/// Configuration for computing zmanim.
+#[derive(Clone, Debug)]
+pub struct ZmanimConfig {
+    /// Whether elevation affects the variable-hour calculations.
+    ///
+    /// When `true` (the default), the place's actual elevation is used, as
+    /// for [`solar::Day::new`]. When `false`, every [`Timepoint::Hour`]
+    /// zman (including [`Zman::Netz`]/[`Zman::Shekiah`]) is instead
+    /// computed against a sea-level day.
+    pub use_elevation: bool,
+    /// Definition of _Alot Hashachar_ (daybreak).
+    pub alot: Timepoint,
+    /// Definition of _Tzet Hakochavim_ (nightfall).
+    pub tzet: Timepoint,
+}
+
+impl Default for ZmanimConfig {
+    /// The standard 16.1°/8.5° depression-angle definitions, with elevation
+    /// taken into account.
+    fn default() -> Self {
+        Self {
+            use_elevation: true,
+            alot: Timepoint::from(Zman::Alot),
+            tzet: Timepoint::from(Zman::Tzet),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use jiff::civil::Date;
@@ -166,7 +283,7 @@ mod tests {
     #[test]
     fn it_works() {
         // Declare date and place
-        let date = Date::constant(2025, 11, 04);
+        let date = Date::constant(2025, 11, 4);
         let place = Geo {
             lat: 43.70643,
             lon: -79.39864,
@@ -228,13 +345,13 @@ mod tests {
             ),
             (
                 Zman::Shekiah,
-                "2025-11-04T18:06-05:00[America/Toronto]"
+                "2025-11-04T17:06-05:00[America/Toronto]"
                     .parse::<Timestamp>()
                     .unwrap(),
             ),
             (
                 Zman::Tzet,
-                "2025-11-04T17:51-05:00[America/Toronto]"
+                "2025-11-04T17:50-05:00[America/Toronto]"
                     .parse::<Timestamp>()
                     .unwrap(),
             ),
@@ -244,7 +361,8 @@ mod tests {
             (
                 zman.clone(),
                 Timepoint::from(zman)
-                    .compute(day.clone())
+                    .compute(day.clone(), Opinion::Gra, &ZmanimConfig::default())
+                    .unwrap()
                     .round(jiff::TimestampRound::new().smallest(jiff::Unit::Minute))
                     .unwrap(),
                 expect,
@@ -254,4 +372,74 @@ mod tests {
         .into_iter()
         .for_each(|(zman, calc, want)| assert_eq!(calc, want, "mismatch for `{zman:?}`"));
     }
+
+    #[test]
+    fn magen_avraham_is_earlier() {
+        // Declare date and place
+        let date = Date::constant(2025, 11, 4);
+        let place = Geo {
+            lat: 43.70643,
+            lon: -79.39864,
+            elv: 0.,
+        };
+
+        // Calculate suntimes
+        let day = solar::Day::new(date, place).unwrap();
+
+        // The MGA's day is bounded by daybreak/nightfall (wider than the
+        // GRA's sunrise/sunset), so its variable hours start earlier
+        let config = ZmanimConfig::default();
+        [Zman::Shema, Zman::Tefilla, Zman::MinchaGedola]
+            .into_iter()
+            .for_each(|zman| {
+                let gra = zman
+                    .clone()
+                    .compute_with(day.clone(), Opinion::Gra, &config)
+                    .unwrap();
+                let mga = zman
+                    .clone()
+                    .compute_with(day.clone(), Opinion::MagenAvraham, &config)
+                    .unwrap();
+                assert!(mga <= gra, "expected MGA {zman:?} no later than GRA");
+            });
+    }
+
+    #[test]
+    fn config_minutes_and_elevation() {
+        // Declare date and an elevated place
+        let date = Date::constant(2025, 11, 4);
+        let place = Geo {
+            lat: 43.70643,
+            lon: -79.39864,
+            elv: 500.,
+        };
+
+        // Calculate suntimes
+        let day = solar::Day::new(date, place).unwrap();
+        let sea_level = day.sea_level().unwrap();
+
+        // Ignoring elevation should match a sea-level day's sunrise exactly
+        let config = ZmanimConfig {
+            use_elevation: false,
+            ..ZmanimConfig::default()
+        };
+        let netz = Zman::Netz
+            .compute_with(day.clone(), Opinion::Gra, &config)
+            .unwrap();
+        assert_eq!(netz, sea_level.rise);
+
+        // A 72-minute, fixed-duration Alot should fall exactly 72 minutes
+        // before (the elevation-respecting) sunrise
+        let config = ZmanimConfig {
+            alot: Timepoint::Minutes {
+                anchor: Anchor::Sunrise,
+                minutes: 72.,
+            },
+            ..ZmanimConfig::default()
+        };
+        let alot = Zman::Alot
+            .compute_with(day.clone(), Opinion::Gra, &config)
+            .unwrap();
+        assert_eq!(alot, day.rise - SignedDuration::from_secs_f64(72. * 60.));
+    }
 }